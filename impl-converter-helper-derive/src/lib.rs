@@ -0,0 +1,231 @@
+//! Companion proc-macro crate for `impl-converter-helper`.
+//!
+//! `#[derive(FromConverter)]` and `#[derive(TryFromConverter)]` read `#[convert(...)]`
+//! attributes on a struct or enum and lower straight to an [`impl_converter_helper::from`] /
+//! [`impl_converter_helper::try_from`] invocation, so the two front-ends (macro call vs.
+//! derive) always produce the same impl. Enable the `derive` feature of
+//! `impl-converter-helper` to pull this crate in and re-export the two derives.
+//!
+//! ## Attributes
+//! - `#[convert(from = "SourceType")]` on the struct/enum itself: the source type to convert
+//!   from. Required.
+//! - `#[convert(error = "ErrorType")]` on the struct/enum itself: the `Error` associated type of
+//!   the generated `TryFrom` impl. Required for `TryFromConverter`, ignored by `FromConverter`.
+//! - `#[convert(with = "expr")]` on a field: use `expr` instead of `src.field.into()` (or
+//!   `.try_into()?` for `TryFromConverter`).
+//! - `#[convert(rename = "OtherVariant")]` on an enum variant: the source variant has a
+//!   different name, using the `as`-rename form of [`impl_converter_helper::from`].
+//!
+//! ## Example
+//! ```
+//! use impl_converter_helper_derive::{FromConverter, TryFromConverter};
+//!
+//! struct SourceStruct { id: u64, raw_name: String }
+//!
+//! #[derive(FromConverter)]
+//! #[convert(from = "SourceStruct")]
+//! struct TargetStruct {
+//!     id: u64,
+//!     #[convert(with = "src.raw_name.trim().to_string()")]
+//!     name: String,
+//! }
+//!
+//! let target: TargetStruct = SourceStruct { id: 1, raw_name: "  Alice  ".to_string() }.into();
+//! assert_eq!("Alice", target.name);
+//!
+//! enum SourceEnum { Case4(i64), CaseY }
+//!
+//! #[derive(TryFromConverter)]
+//! #[convert(from = "SourceEnum")]
+//! #[convert(error = "std::num::TryFromIntError")]
+//! enum TargetEnum {
+//!     #[convert(rename = "Case4")]
+//!     CaseX(i32),
+//!     CaseY,
+//! }
+//!
+//! let target = TargetEnum::try_from(SourceEnum::Case4(42)).unwrap();
+//! assert!(matches!(target, TargetEnum::CaseX(42)));
+//! ```
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Fields, Lit, Meta, NestedMeta};
+
+/// `#[derive(FromConverter)]`: emits the `impl From<SourceType> for Self` that
+/// `impl_converter_helper::from!` would emit for the equivalent `as struct`/`as enum` call.
+#[proc_macro_derive(FromConverter, attributes(convert))]
+pub fn derive_from_converter(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, Kind::From)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// `#[derive(TryFromConverter)]`: emits the `impl TryFrom<SourceType> for Self` that
+/// `impl_converter_helper::try_from!` would emit for the equivalent `as struct`/`as enum` call.
+#[proc_macro_derive(TryFromConverter, attributes(convert))]
+pub fn derive_try_from_converter(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, Kind::TryFrom)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    From,
+    TryFrom,
+}
+
+fn expand(input: &DeriveInput, kind: Kind) -> syn::Result<proc_macro2::TokenStream> {
+    let target = &input.ident;
+
+    let source = find_convert_str(&input.attrs, "from").ok_or_else(|| {
+        syn::Error::new_spanned(
+            input,
+            "missing `#[convert(from = \"SourceType\")]` on the type deriving FromConverter/TryFromConverter",
+        )
+    })?;
+    let source_ty: syn::Type = syn::parse_str(&source)?;
+
+    // `from!`'s expansion doesn't reference the error type, so a unit placeholder is fine here;
+    // only the `Kind::TryFrom` branches below ever emit it.
+    let error_ty: syn::Type = match kind {
+        Kind::From => parse_quote!(()),
+        Kind::TryFrom => {
+            let error = find_convert_str(&input.attrs, "error").ok_or_else(|| {
+                syn::Error::new_spanned(
+                    input,
+                    "missing `#[convert(error = \"ErrorType\")]` on the type deriving TryFromConverter",
+                )
+            })?;
+            syn::parse_str(&error)?
+        }
+    };
+
+    match &input.data {
+        Data::Struct(data) => expand_struct(target, &source_ty, &error_ty, &data.fields, kind),
+        Data::Enum(data) => expand_enum(target, &source_ty, &error_ty, data, kind),
+        Data::Union(_) => Err(syn::Error::new_spanned(
+            input,
+            "FromConverter/TryFromConverter cannot be derived for unions",
+        )),
+    }
+}
+
+fn expand_struct(
+    target: &syn::Ident,
+    source_ty: &syn::Type,
+    error_ty: &syn::Type,
+    fields: &Fields,
+    kind: Kind,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let field_arms = fields
+        .iter()
+        .map(|field| {
+            let name = field.ident.as_ref().ok_or_else(|| {
+                syn::Error::new_spanned(
+                    field,
+                    "tuple structs are not supported by FromConverter/TryFromConverter",
+                )
+            })?;
+            match find_convert_str(&field.attrs, "with") {
+                Some(expr) => {
+                    let expr: syn::Expr = syn::parse_str(&expr)?;
+                    Ok(quote! { #name: #expr })
+                }
+                None => Ok(quote! { #name }),
+            }
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(match kind {
+        Kind::From => quote! {
+            ::impl_converter_helper::from!((src: #source_ty) -> #target as struct {
+                #(#field_arms),*
+            });
+        },
+        Kind::TryFrom => quote! {
+            ::impl_converter_helper::try_from!((src: #source_ty) -> <#target, #error_ty> as struct {
+                #(#field_arms),*
+            });
+        },
+    })
+}
+
+fn expand_enum(
+    target: &syn::Ident,
+    source_ty: &syn::Type,
+    error_ty: &syn::Type,
+    data: &syn::DataEnum,
+    kind: Kind,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let variant_arms = data
+        .variants
+        .iter()
+        .map(|variant| {
+            let dst_name = &variant.ident;
+            let bindings = match &variant.fields {
+                Fields::Unit => vec![],
+                Fields::Unnamed(fields) => (0..fields.unnamed.len())
+                    .map(|i| format_ident!("v{i}"))
+                    .collect(),
+                Fields::Named(_) => {
+                    return Err(syn::Error::new_spanned(
+                        variant,
+                        "FromConverter/TryFromConverter does not support enum variants with named fields",
+                    ));
+                }
+            };
+            let binding_group = if bindings.is_empty() {
+                quote! {}
+            } else {
+                quote! { (#(#bindings),*) }
+            };
+
+            match find_convert_str(&variant.attrs, "rename") {
+                Some(src_name) => {
+                    let src_name: syn::Ident = syn::parse_str(&src_name)?;
+                    Ok(quote! { #src_name #binding_group as #dst_name #binding_group })
+                }
+                None => Ok(quote! { #dst_name #binding_group }),
+            }
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(match kind {
+        Kind::From => quote! {
+            ::impl_converter_helper::from!((src: #source_ty) -> #target as enum {
+                #(#variant_arms),*
+            });
+        },
+        Kind::TryFrom => quote! {
+            ::impl_converter_helper::try_from!((src: #source_ty) -> <#target, #error_ty> as enum {
+                #(#variant_arms),*
+            });
+        },
+    })
+}
+
+/// Reads the value of `#[convert(<key> = "...")]` off a list of attributes, if present.
+fn find_convert_str(attrs: &[syn::Attribute], key: &str) -> Option<String> {
+    for attr in attrs {
+        if !attr.path.is_ident("convert") {
+            continue;
+        }
+        let Ok(Meta::List(list)) = attr.parse_meta() else {
+            continue;
+        };
+        for nested in list.nested {
+            if let NestedMeta::Meta(Meta::NameValue(nv)) = nested {
+                if nv.path.is_ident(key) {
+                    if let Lit::Str(s) = nv.lit {
+                        return Some(s.value());
+                    }
+                }
+            }
+        }
+    }
+    None
+}
@@ -54,6 +54,57 @@
 //! ```
 //! You can also use the `as struct` or `as enum` keywords to convert between `struct` types or `enum` types.
 //! See the details at [try_from].
+//!
+//! ## Implementing both directions at once
+//! The [from_bidi] and [try_from_bidi] macros emit the two `From`/`TryFrom` impls of a
+//! conversion pair (`A -> B` and `B -> A`) from a single declaration, so the two directions
+//! can't drift apart. See the details at [from_bidi] and [try_from_bidi].
+//!
+//! ## Lifting a conversion to a container
+//! The [lift] macro takes an existing element-level `From`/`TryFrom`/`ForceFrom` impl and
+//! generates a named function that applies it over a `Vec`/`Option`/`HashMap` of that element,
+//! so you don't have to write `.into_iter().map(...).collect()` at every call site that embeds a
+//! collection of convertible values. See the details at [lift].
+//!
+//! ## Deriving the impls instead of invoking a macro
+//! With the `derive` feature enabled, `#[derive(FromConverter)]` and `#[derive(TryFromConverter)]`
+//! read `#[convert(...)]` attributes on the type and emit the same impls that [from]/[try_from]
+//! would, letting you annotate the target type directly instead of writing a separate macro
+//! invocation for it.
+
+/// DON'T USE! This can only be used within the `as struct` forms of [from], [try_from] and
+/// [force_from]. Resolves the `..` spread tail: `..` (no expr) falls back to
+/// `Default::default()`, `..expr` passes `expr` through unchanged.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __struct_spread_base {
+    () => {
+        ::std::default::Default::default()
+    };
+    ($base:expr) => {
+        $base
+    };
+}
+
+/// Lets a warning or error type carry a breadcrumb describing where, in a nested conversion, it
+/// was produced.
+///
+/// Used by the opt-in `@context` mode of [try_from] and [force_from]: each field or variant
+/// prefixes its warnings/errors with its own name via [WithContext::with_context]. The impl
+/// provided for [String] adds a `name: ` prefix per level, so a warning raised two levels deep
+/// reads `outer: inner: message`. Fields and variants given an explicit expression are not
+/// wrapped (except `@warn` fields of [force_from]), and [lift] adds no breadcrumb for collection
+/// elements. Implement it for your own warning/error type to get the same breadcrumb behavior.
+pub trait WithContext {
+    /// Prefixes `self` with `ctx`, the name of the field or variant that produced it.
+    fn with_context(self, ctx: &str) -> Self;
+}
+
+impl WithContext for String {
+    fn with_context(self, ctx: &str) -> Self {
+        format!("{ctx}: {self}")
+    }
+}
 
 /// DON'T USE! This can only be used within the [from] macro.
 #[doc(hidden)]
@@ -73,6 +124,9 @@ macro_rules! __from_struct_field {
 macro_rules! __from_enum_variant {
     ($variant:ident $(($($var:ident),*))?) => { Self::$variant$(($($var.into()),*))? };
     ($variant:ident $(($($var:ident),*))? => $value:expr) => { $value };
+    ($variant:ident $(($($var:ident),*))? as $dst_variant:ident $(($($dst_var:ident),*))?) => {
+        Self::$dst_variant$(($($var.into()),*))?
+    };
 }
 
 /// Helper to `impl From<$src_type> for $dst_type`.
@@ -87,11 +141,14 @@ macro_rules! __from_enum_variant {
 /// #[derive(Debug, PartialEq, Eq)]
 /// struct StructB { num: i64, text: String }
 ///
+/// #[derive(Debug, Default, PartialEq, Eq)]
+/// struct StructC { num: i64, flag: bool }
+///
 /// #[derive(Debug, PartialEq, Eq)]
-/// enum EnumA { Case1, Case2(i32), Case3(StructA, i32), Case4(String, i32) }
+/// enum EnumA { Case1, Case2(i32), Case3(StructA, i32), Case4(String, i32), Case5(i32) }
 ///
 /// #[derive(Debug, PartialEq, Eq)]
-/// enum EnumB { Case1, Case2(i64), Case3(StructB, i64), CaseX(String) }
+/// enum EnumB { Case1, Case2(i64), Case3(StructB, i64), CaseX(String), CaseY(i64) }
 ///
 /// // convert struct to struct
 /// from!((src: StructA) -> StructB as struct {
@@ -100,20 +157,35 @@ macro_rules! __from_enum_variant {
 /// });
 /// assert_eq!(StructB { num: 123, text: "num = 123".into() }, StructA { num: 123 }.into());
 ///
+/// // leave unlisted target fields at their `Default::default()`, or an arbitrary base value
+/// from!((src: StructA) -> StructC as struct { num, .. });
+/// assert_eq!(StructC { num: 123, flag: false }, StructA { num: 123 }.into());
+///
 /// // convert enum to enum
 /// from!((src: EnumA) -> EnumB as enum {
 ///     Case1,
 ///     Case2(n),
 ///     Case3(x, n),
 ///     Case4(s, n) => Self::CaseX(format!("{s}_{n}")),
+///     // `as` renames the variant while still converting each field with `.into()`
+///     Case5(n) as CaseY(n),
 /// });
 /// assert_eq!(EnumB::Case2(321), EnumA::Case2(321).into());
+/// assert_eq!(EnumB::CaseY(7), EnumA::Case5(7).into());
 ///
 /// // convert anyway
 /// from!((src: StructA) -> EnumA {
 ///     Self::Case2(src.num)
 /// });
 /// assert_eq!(EnumA::Case2(111), StructA { num: 111 }.into());
+///
+/// // convert a generic type, with an optional `where` clause
+/// struct Wrapper<T>(T);
+/// struct Labeled<T>(T);
+/// from!([T: Into<U>, U] (src: Wrapper<T>) -> Labeled<U> {
+///     Labeled(src.0.into())
+/// } where { T: Clone });
+/// assert_eq!(42i64, Labeled::<i64>::from(Wrapper(42i32)).0);
 /// ```
 #[macro_export]
 macro_rules! from {
@@ -130,22 +202,77 @@ macro_rules! from {
     }) => {
         $crate::from!(($src: $src_type) -> $dst_type {
             Self {
-                $($field: $crate::__from_struct_field!($src.$field $(=> $value)?)),*
+                $($field: $crate::__from_struct_field!($src.$field $(=> $value)?),)*
+            }
+        });
+    };
+
+    // convert struct type, defaulting unlisted target fields via a `..` spread tail
+    (($src:ident : $src_type:ty) -> $dst_type:ty as struct {
+        $($field:ident$(: $value:expr)?,)* .. $($base:expr)?$(,)?
+    }) => {
+        $crate::from!(($src: $src_type) -> $dst_type {
+            Self {
+                $($field: $crate::__from_struct_field!($src.$field $(=> $value)?),)*
+                .. $crate::__struct_spread_base!($($base)?)
             }
         });
     };
 
     // convert enum type
     (($src:ident : $src_type:ty) -> $dst_type:ty as enum {
-        $($variant:ident$(($($var:ident),*))?$(=> $value:expr)?),*$(,)?
+        $($variant:ident$(($($var:ident),*))?$(as $dst_variant:ident $(($($dst_var:ident),*))?)?$(=> $value:expr)?),*$(,)?
     }) => {
         $crate::from!(($src: $src_type) -> $dst_type {
             type Src = $src_type;
             match $src {
-                $(Src::$variant$(($($var),*))? => $crate::__from_enum_variant!($variant$(($($var),*))? $(=> $value)?)),*
+                $(Src::$variant$(($($var),*))? => $crate::__from_enum_variant!($variant$(($($var),*))? $(=> $value)? $(as $dst_variant $(($($dst_var),*))?)?)),*
             }
         });
     };
+
+    // impl From<$src_type> for $dst_type, with generics and an optional where clause
+    ([$($generics:tt)*] ($src:ident : $src_type:ty) -> $dst_type:ty $block:block $(where { $($where_clause:tt)* })?) => {
+        impl<$($generics)*> ::std::convert::From<$src_type> for $dst_type $(where $($where_clause)*)? {
+            fn from($src: $src_type) -> Self $block
+        }
+    };
+
+    // convert struct type, with generics and an optional where clause
+    ([$($generics:tt)*] ($src:ident : $src_type:ty) -> $dst_type:ty as struct {
+        $($field:ident$(: $value:expr)?),*$(,)?
+    } $(where { $($where_clause:tt)* })?) => {
+        $crate::from!([$($generics)*] ($src: $src_type) -> $dst_type {
+            Self {
+                $($field: $crate::__from_struct_field!($src.$field $(=> $value)?),)*
+            }
+        } $(where { $($where_clause)* })?);
+    };
+
+    // convert struct type, with generics and an optional where clause, defaulting unlisted
+    // target fields via a `..` spread tail
+    ([$($generics:tt)*] ($src:ident : $src_type:ty) -> $dst_type:ty as struct {
+        $($field:ident$(: $value:expr)?,)* .. $($base:expr)?$(,)?
+    } $(where { $($where_clause:tt)* })?) => {
+        $crate::from!([$($generics)*] ($src: $src_type) -> $dst_type {
+            Self {
+                $($field: $crate::__from_struct_field!($src.$field $(=> $value)?),)*
+                .. $crate::__struct_spread_base!($($base)?)
+            }
+        } $(where { $($where_clause)* })?);
+    };
+
+    // convert enum type, with generics and an optional where clause
+    ([$($generics:tt)*] ($src:ident : $src_type:ty) -> $dst_type:ty as enum {
+        $($variant:ident$(($($var:ident),*))?$(as $dst_variant:ident $(($($dst_var:ident),*))?)?$(=> $value:expr)?),*$(,)?
+    } $(where { $($where_clause:tt)* })?) => {
+        $crate::from!([$($generics)*] ($src: $src_type) -> $dst_type {
+            type Src = $src_type;
+            match $src {
+                $(Src::$variant$(($($var),*))? => $crate::__from_enum_variant!($variant$(($($var),*))? $(=> $value)? $(as $dst_variant $(($($dst_var),*))?)?)),*
+            }
+        } $(where { $($where_clause)* })?);
+    };
 }
 
 // ------------------------------------------------------------
@@ -162,12 +289,46 @@ macro_rules! __try_from_struct_field {
     };
 }
 
+/// DON'T USE! This can only be used within the `@context` form of the [try_from] macro.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __try_from_struct_field_ctx {
+    ($src:ident.$field:ident) => {
+        $src.$field
+            .try_into()
+            .map_err(|e| $crate::WithContext::with_context(e, stringify!($field)))?
+    };
+    ($src:ident.$field:ident => $value:expr) => {
+        $value
+    };
+}
+
 /// DON'T USE! This can only be used within the [try_from] macro.
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __try_from_enum_variant {
     ($variant:ident $(($($var:ident),*))?) => { Ok(Self::$variant$(($($var.try_into()?),*))?)  };
     ($variant:ident $(($($var:ident),*))? => $value:expr) => { $value };
+    ($variant:ident $(($($var:ident),*))? as $dst_variant:ident $(($($dst_var:ident),*))?) => {
+        Ok(Self::$dst_variant$(($($var.try_into()?),*))?)
+    };
+}
+
+/// DON'T USE! This can only be used within the `@context` form of the [try_from] macro.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __try_from_enum_variant_ctx {
+    ($variant:ident $(($($var:ident),*))?) => {
+        Ok(Self::$variant$(($(
+            $var.try_into().map_err(|e| $crate::WithContext::with_context(e, stringify!($variant)))?
+        ),*))?)
+    };
+    ($variant:ident $(($($var:ident),*))? => $value:expr) => { $value };
+    ($variant:ident $(($($var:ident),*))? as $dst_variant:ident $(($($dst_var:ident),*))?) => {
+        Ok(Self::$dst_variant$(($(
+            $var.try_into().map_err(|e| $crate::WithContext::with_context(e, stringify!($variant)))?
+        ),*))?)
+    };
 }
 
 /// Helper to `impl TryFrom<$src_type> for $dst_type`.
@@ -184,10 +345,10 @@ macro_rules! __try_from_enum_variant {
 /// struct StructB { num: i64, text: String }
 ///
 /// #[derive(Debug, PartialEq, Eq)]
-/// enum EnumA { Case1, Case2(i32), Case3(StructA, i32), Case4(String) }
+/// enum EnumA { Case1, Case2(i32), Case3(StructA, i32), Case4(String), Case5(i32) }
 ///
 /// #[derive(Debug, PartialEq, Eq)]
-/// enum EnumB { Case1, Case2(i64), Case3(StructB, i64) }
+/// enum EnumB { Case1, Case2(i64), Case3(StructB, i64), CaseY(i64) }
 ///
 /// // convert struct to struct
 /// try_from!((src: StructA) -> <StructB, anyhow::Error> as struct {
@@ -202,14 +363,48 @@ macro_rules! __try_from_enum_variant {
 ///     Case2(n),
 ///     Case3(x, n),
 ///     Case4(s) => Err(anyhow::anyhow!("error")),
+///     // `as` renames the variant while still converting each field with `.try_into()?`
+///     Case5(n) as CaseY(n),
 /// });
 /// assert_eq!(EnumB::Case2(321), EnumA::Case2(321).try_into().unwrap());
+/// assert_eq!(EnumB::CaseY(7), EnumA::Case5(7).try_into().unwrap());
+///
+/// // opt into `@context` to have each field's error carry its field name as a breadcrumb
+/// struct Digit(i32);
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// struct DigitChecked(i32);
+///
+/// impl TryFrom<Digit> for DigitChecked {
+///     type Error = String;
+///     fn try_from(d: Digit) -> Result<Self, String> {
+///         if (0..10).contains(&d.0) { Ok(Self(d.0)) } else { Err("out of range".to_string()) }
+///     }
+/// }
+///
+/// struct NumA { value: Digit }
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// struct NumB { value: DigitChecked }
+///
+/// try_from!((src: NumA) -> <NumB, String> as struct @context {
+///     value,
+/// });
+/// assert_eq!(Err("value: out of range".to_string()), NumB::try_from(NumA { value: Digit(42) }));
 ///
 /// // convert anyway
 /// try_from!((src: StructA) -> <EnumA, anyhow::Error> {
 ///     Ok(Self::Case2(src.num))
 /// });
 /// assert_eq!(EnumA::Case2(111), StructA { num: 111 }.try_into().unwrap());
+///
+/// // convert a generic type, with an optional `where` clause
+/// struct Wrapper<T>(T);
+/// struct Labeled<T>(T);
+/// try_from!([T: TryInto<U>, U] (src: Wrapper<T>) -> <Labeled<U>, T::Error> {
+///     Ok(Labeled(src.0.try_into()?))
+/// } where { T: Clone });
+/// assert_eq!(42i64, Labeled::<i64>::try_from(Wrapper(42i32)).unwrap().0);
 /// ```
 #[macro_export]
 macro_rules! try_from {
@@ -232,17 +427,333 @@ macro_rules! try_from {
         });
     };
 
+    // convert struct type, defaulting unlisted target fields via a `..` spread tail
+    (($src:ident : $src_type:ty) -> <$dst_type:ty, $err_type:ty> as struct {
+        $($field:ident$(: $value:expr)?,)* .. $($base:expr)?$(,)?
+    }) => {
+        $crate::try_from!(($src: $src_type) -> <$dst_type, $err_type> {
+            Ok(Self {
+                $($field: $crate::__try_from_struct_field!($src.$field $(=> $value)?),)*
+                .. $crate::__struct_spread_base!($($base)?)
+            })
+        });
+    };
+
     // convert enum type
     (($src:ident : $src_type:ty) -> <$dst_type:ty, $err_type:ty> as enum {
-        $($variant:ident$(($($var:ident),*))?$(=> $value:expr)?),*$(,)?
+        $($variant:ident$(($($var:ident),*))?$(as $dst_variant:ident $(($($dst_var:ident),*))?)?$(=> $value:expr)?),*$(,)?
     }) => {
         $crate::try_from!(($src: $src_type) -> <$dst_type, $err_type> {
             type Src = $src_type;
             match $src {
-                $(Src::$variant$(($($var),*))? => $crate::__try_from_enum_variant!($variant$(($($var),*))? $(=> $value)?),)*
+                $(Src::$variant$(($($var),*))? => $crate::__try_from_enum_variant!($variant$(($($var),*))? $(=> $value)? $(as $dst_variant $(($($dst_var),*))?)?),)*
             }
         });
     };
+
+    // convert struct type, wrapping each field's error with its field name as a breadcrumb
+    (($src:ident : $src_type:ty) -> <$dst_type:ty, $err_type:ty> as struct @context {
+        $($field:ident$(: $value:expr)?),*$(,)?
+    }) => {
+        $crate::try_from!(($src: $src_type) -> <$dst_type, $err_type> {
+            Ok(Self {
+                $($field: $crate::__try_from_struct_field_ctx!($src.$field $(=> $value)?),)*
+            })
+        });
+    };
+
+    // convert struct type, wrapping each field's error with its field name as a breadcrumb,
+    // defaulting unlisted target fields via a `..` spread tail
+    (($src:ident : $src_type:ty) -> <$dst_type:ty, $err_type:ty> as struct @context {
+        $($field:ident$(: $value:expr)?,)* .. $($base:expr)?$(,)?
+    }) => {
+        $crate::try_from!(($src: $src_type) -> <$dst_type, $err_type> {
+            Ok(Self {
+                $($field: $crate::__try_from_struct_field_ctx!($src.$field $(=> $value)?),)*
+                .. $crate::__struct_spread_base!($($base)?)
+            })
+        });
+    };
+
+    // convert enum type, wrapping each variant's error with its variant name as a breadcrumb
+    (($src:ident : $src_type:ty) -> <$dst_type:ty, $err_type:ty> as enum @context {
+        $($variant:ident$(($($var:ident),*))?$(as $dst_variant:ident $(($($dst_var:ident),*))?)?$(=> $value:expr)?),*$(,)?
+    }) => {
+        $crate::try_from!(($src: $src_type) -> <$dst_type, $err_type> {
+            type Src = $src_type;
+            match $src {
+                $(Src::$variant$(($($var),*))? => $crate::__try_from_enum_variant_ctx!($variant$(($($var),*))? $(=> $value)? $(as $dst_variant $(($($dst_var),*))?)?),)*
+            }
+        });
+    };
+
+    // impl TryFrom<$src_type> for $dst_type, with generics and an optional where clause
+    ([$($generics:tt)*] ($src:ident : $src_type:ty) -> <$dst_type:ty, $err_type:ty> $block:block $(where { $($where_clause:tt)* })?) => {
+        impl<$($generics)*> ::std::convert::TryFrom<$src_type> for $dst_type $(where $($where_clause)*)? {
+            type Error = $err_type;
+            fn try_from($src: $src_type) -> ::std::result::Result<Self, Self::Error> $block
+        }
+    };
+
+    // convert struct type, with generics and an optional where clause
+    ([$($generics:tt)*] ($src:ident : $src_type:ty) -> <$dst_type:ty, $err_type:ty> as struct {
+        $($field:ident$(: $value:expr)?),*$(,)?
+    } $(where { $($where_clause:tt)* })?) => {
+        $crate::try_from!([$($generics)*] ($src: $src_type) -> <$dst_type, $err_type> {
+            Ok(Self {
+                $($field: $crate::__try_from_struct_field!($src.$field $(=> $value)?),)*
+            })
+        } $(where { $($where_clause)* })?);
+    };
+
+    // convert struct type, with generics and an optional where clause, defaulting unlisted
+    // target fields via a `..` spread tail
+    ([$($generics:tt)*] ($src:ident : $src_type:ty) -> <$dst_type:ty, $err_type:ty> as struct {
+        $($field:ident$(: $value:expr)?,)* .. $($base:expr)?$(,)?
+    } $(where { $($where_clause:tt)* })?) => {
+        $crate::try_from!([$($generics)*] ($src: $src_type) -> <$dst_type, $err_type> {
+            Ok(Self {
+                $($field: $crate::__try_from_struct_field!($src.$field $(=> $value)?),)*
+                .. $crate::__struct_spread_base!($($base)?)
+            })
+        } $(where { $($where_clause)* })?);
+    };
+
+    // convert enum type, with generics and an optional where clause
+    ([$($generics:tt)*] ($src:ident : $src_type:ty) -> <$dst_type:ty, $err_type:ty> as enum {
+        $($variant:ident$(($($var:ident),*))?$(as $dst_variant:ident $(($($dst_var:ident),*))?)?$(=> $value:expr)?),*$(,)?
+    } $(where { $($where_clause:tt)* })?) => {
+        $crate::try_from!([$($generics)*] ($src: $src_type) -> <$dst_type, $err_type> {
+            type Src = $src_type;
+            match $src {
+                $(Src::$variant$(($($var),*))? => $crate::__try_from_enum_variant!($variant$(($($var),*))? $(=> $value)? $(as $dst_variant $(($($dst_var),*))?)?),)*
+            }
+        } $(where { $($where_clause)* })?);
+    };
+}
+
+// ----------------------------------------------------------------
+
+/// DON'T USE! This can only be used within the [from_bidi] macro.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __from_bidi_enum {
+    // done: emit the two `as enum` conversions built up so far.
+    (@accum ($src:ident : $src_type:ty) ($dst:ident : $dst_type:ty) ()
+        -> (fwd: [$($fwd:tt)*]) (bwd: [$($bwd:tt)*])
+    ) => {
+        $crate::from!(($src: $src_type) -> $dst_type as enum { $($fwd)* });
+        $crate::from!(($dst: $dst_type) -> $src_type as enum { $($bwd)* });
+    };
+
+    // a variant renamed between the two sides: `Case4(s, n) <-> CaseX(a, b)`.
+    (@accum ($src:ident : $src_type:ty) ($dst:ident : $dst_type:ty)
+        ($svariant:ident $(($($svar:ident),*))? <-> $dvariant:ident $(($($dvar:ident),*))? $(, $($rest:tt)*)?)
+        -> (fwd: [$($fwd:tt)*]) (bwd: [$($bwd:tt)*])
+    ) => {
+        $crate::__from_bidi_enum! {
+            @accum ($src: $src_type) ($dst: $dst_type) ($($($rest)*)?)
+            -> (fwd: [$($fwd)* $svariant $(($($svar),*))? => Self::$dvariant $(($($svar.into()),*))?,])
+               (bwd: [$($bwd)* $dvariant $(($($dvar),*))? => Self::$svariant $(($($dvar.into()),*))?,])
+        }
+    };
+
+    // a variant shared by both sides under the same name: `Case2(n)`.
+    (@accum ($src:ident : $src_type:ty) ($dst:ident : $dst_type:ty)
+        ($variant:ident $(($($var:ident),*))? $(, $($rest:tt)*)?)
+        -> (fwd: [$($fwd:tt)*]) (bwd: [$($bwd:tt)*])
+    ) => {
+        $crate::__from_bidi_enum! {
+            @accum ($src: $src_type) ($dst: $dst_type) ($($($rest)*)?)
+            -> (fwd: [$($fwd)* $variant $(($($var),*))?,])
+               (bwd: [$($bwd)* $variant $(($($var),*))?,])
+        }
+    };
+}
+
+/// Helper to `impl From<$a_type> for $b_type` *and* `impl From<$b_type> for $a_type` from a
+/// single declaration.
+///
+/// This is the bidirectional counterpart of [from]: instead of writing two near-duplicate
+/// `from!` calls (one per direction) and keeping them in sync by hand, you write the mapping
+/// once. For `as struct`, each entry pairs the target-side field with the source-side field it
+/// came from, `dst_field <-> src_field`, optionally overriding either direction's expression.
+/// For `as enum`, a variant shared by both sides is listed once (e.g. `Case2(n)`), and a variant
+/// renamed between the two sides is listed as `Case4(s, n) <-> CaseX(a, b)`.
+///
+/// A custom override expression must be wrapped in braces (`{ ... }`) rather than written bare,
+/// since `macro_rules!` can't place an `<->` token directly after an unbraced expression.
+///
+/// # Example
+/// ```
+/// use impl_converter_helper::*;
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// struct StructA { num: i32, tag: i32 }
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// struct StructB { num: i32, text: String }
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// enum EnumA { Case1, Case2(i32), Case4(String, i32) }
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// enum EnumB { Case1, Case2(i32), CaseX(String, i32) }
+///
+/// // convert struct to struct, both directions
+/// from_bidi!((src: StructA) <-> (dst: StructB) as struct {
+///     num <-> num,
+///     text: { format!("tag = {}", src.tag) } <-> tag: { dst.text.len() as i32 },
+/// });
+/// assert_eq!(StructB { num: 123, text: "tag = 4".into() }, StructA { num: 123, tag: 4 }.into());
+/// let back: StructA = StructB { num: 1, text: "abc".into() }.into();
+/// assert_eq!(StructA { num: 1, tag: 3 }, back);
+///
+/// // convert enum to enum, both directions
+/// from_bidi!((src: EnumA) <-> (dst: EnumB) as enum {
+///     Case1,
+///     Case2(n),
+///     Case4(s, n) <-> CaseX(a, b),
+/// });
+/// assert_eq!(EnumB::Case2(321), EnumA::Case2(321).into());
+/// assert_eq!(EnumA::Case2(321), EnumB::Case2(321).into());
+/// ```
+#[macro_export]
+macro_rules! from_bidi {
+    // both directions from a pair of plain blocks
+    (($src:ident : $src_type:ty) <-> ($dst:ident : $dst_type:ty) {
+        $fwd_block:block
+    } {
+        $bwd_block:block
+    }) => {
+        $crate::from!(($src: $src_type) -> $dst_type $fwd_block);
+        $crate::from!(($dst: $dst_type) -> $src_type $bwd_block);
+    };
+
+    // both directions between struct types
+    (($src:ident : $src_type:ty) <-> ($dst:ident : $dst_type:ty) as struct {
+        $($dst_field:ident $(: $fwd_value:block)? <-> $src_field:ident $(: $bwd_value:block)?),*$(,)?
+    }) => {
+        $crate::from!(($src: $src_type) -> $dst_type as struct {
+            $($dst_field: $crate::__from_struct_field!($src.$src_field $(=> $fwd_value)?)),*
+        });
+        $crate::from!(($dst: $dst_type) -> $src_type as struct {
+            $($src_field: $crate::__from_struct_field!($dst.$dst_field $(=> $bwd_value)?)),*
+        });
+    };
+
+    // both directions between enum types
+    (($src:ident : $src_type:ty) <-> ($dst:ident : $dst_type:ty) as enum {
+        $($items:tt)*
+    }) => {
+        $crate::__from_bidi_enum! {
+            @accum ($src: $src_type) ($dst: $dst_type) ($($items)*)
+            -> (fwd: []) (bwd: [])
+        }
+    };
+}
+
+// ----------------------------------------------------------------
+
+/// DON'T USE! This can only be used within the [try_from_bidi] macro.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __try_from_bidi_enum {
+    // done: emit the two `as enum` conversions built up so far.
+    (@accum ($src:ident : $src_type:ty) ($dst:ident : $dst_type:ty) <$err_type:ty>
+        ()
+        -> (fwd: [$($fwd:tt)*]) (bwd: [$($bwd:tt)*])
+    ) => {
+        $crate::try_from!(($src: $src_type) -> <$dst_type, $err_type> as enum { $($fwd)* });
+        $crate::try_from!(($dst: $dst_type) -> <$src_type, $err_type> as enum { $($bwd)* });
+    };
+
+    // a variant renamed between the two sides: `Case4(s, n) <-> CaseX(a, b)`.
+    (@accum ($src:ident : $src_type:ty) ($dst:ident : $dst_type:ty) <$err_type:ty>
+        ($svariant:ident $(($($svar:ident),*))? <-> $dvariant:ident $(($($dvar:ident),*))? $(, $($rest:tt)*)?)
+        -> (fwd: [$($fwd:tt)*]) (bwd: [$($bwd:tt)*])
+    ) => {
+        $crate::__try_from_bidi_enum! {
+            @accum ($src: $src_type) ($dst: $dst_type) <$err_type> ($($($rest)*)?)
+            -> (fwd: [$($fwd)* $svariant $(($($svar),*))? => Ok(Self::$dvariant $(($($svar.try_into()?),*))?),])
+               (bwd: [$($bwd)* $dvariant $(($($dvar),*))? => Ok(Self::$svariant $(($($dvar.try_into()?),*))?),])
+        }
+    };
+
+    // a variant shared by both sides under the same name: `Case2(n)`.
+    (@accum ($src:ident : $src_type:ty) ($dst:ident : $dst_type:ty) <$err_type:ty>
+        ($variant:ident $(($($var:ident),*))? $(, $($rest:tt)*)?)
+        -> (fwd: [$($fwd:tt)*]) (bwd: [$($bwd:tt)*])
+    ) => {
+        $crate::__try_from_bidi_enum! {
+            @accum ($src: $src_type) ($dst: $dst_type) <$err_type> ($($($rest)*)?)
+            -> (fwd: [$($fwd)* $variant $(($($var),*))?,])
+               (bwd: [$($bwd)* $variant $(($($var),*))?,])
+        }
+    };
+}
+
+/// Helper to `impl TryFrom<$a_type> for $b_type` *and* `impl TryFrom<$b_type> for $a_type` from a
+/// single declaration.
+///
+/// This is the bidirectional counterpart of [try_from]; see [from_bidi] for the shared syntax,
+/// including the requirement that a custom override expression be wrapped in braces (`{ ... }`).
+/// Both impls share the same error type `$err_type`. If a field has no sensible inverse, write an
+/// explicit `$bwd_value` (or `$fwd_value`) block that returns an error (e.g.
+/// `{ return Err(...) }`) instead of trying to recover a value. Enum variants have no such
+/// override: every listed variant is converted with `.try_into()?` in both directions.
+///
+/// # Example
+/// ```
+/// use impl_converter_helper::*;
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// struct StructA { num: i32, tag: i32 }
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// struct StructB { num: i64, text: String }
+///
+/// try_from_bidi!((src: StructA) <-> (dst: StructB) as <anyhow::Error> struct {
+///     num <-> num,
+///     text: { format!("tag = {}", src.tag) } <-> tag: { dst.text.len() as i32 },
+/// });
+/// assert_eq!(StructB { num: 123, text: "tag = 4".into() }, StructA { num: 123, tag: 4 }.try_into().unwrap());
+/// let back: StructA = StructB { num: 1, text: "abc".into() }.try_into().unwrap();
+/// assert_eq!(StructA { num: 1, tag: 3 }, back);
+/// ```
+#[macro_export]
+macro_rules! try_from_bidi {
+    // both directions from a pair of plain blocks
+    (($src:ident : $src_type:ty) <-> ($dst:ident : $dst_type:ty) as <$err_type:ty> {
+        $fwd_block:block
+    } {
+        $bwd_block:block
+    }) => {
+        $crate::try_from!(($src: $src_type) -> <$dst_type, $err_type> $fwd_block);
+        $crate::try_from!(($dst: $dst_type) -> <$src_type, $err_type> $bwd_block);
+    };
+
+    // both directions between struct types
+    (($src:ident : $src_type:ty) <-> ($dst:ident : $dst_type:ty) as <$err_type:ty> struct {
+        $($dst_field:ident $(: $fwd_value:block)? <-> $src_field:ident $(: $bwd_value:block)?),*$(,)?
+    }) => {
+        $crate::try_from!(($src: $src_type) -> <$dst_type, $err_type> as struct {
+            $($dst_field: $crate::__try_from_struct_field!($src.$src_field $(=> $fwd_value)?)),*
+        });
+        $crate::try_from!(($dst: $dst_type) -> <$src_type, $err_type> as struct {
+            $($src_field: $crate::__try_from_struct_field!($dst.$dst_field $(=> $bwd_value)?)),*
+        });
+    };
+
+    // both directions between enum types
+    (($src:ident : $src_type:ty) <-> ($dst:ident : $dst_type:ty) as <$err_type:ty> enum {
+        $($items:tt)*
+    }) => {
+        $crate::__try_from_bidi_enum! {
+            @accum ($src: $src_type) ($dst: $dst_type) <$err_type> ($($items)*)
+            -> (fwd: []) (bwd: [])
+        }
+    };
 }
 
 // ----------------------------------------------------------------
@@ -250,6 +761,13 @@ macro_rules! try_from {
 #[cfg(feature = "warned")]
 pub use warned;
 
+/// `#[derive(FromConverter)]` / `#[derive(TryFromConverter)]`, generating the same `impl`s that
+/// [from]/[try_from] would for an `as struct`/`as enum` declaration, driven by `#[convert(...)]`
+/// attributes on the type instead of a separate macro invocation. See the
+/// `impl-converter-helper-derive` crate for the attribute syntax.
+#[cfg(feature = "derive")]
+pub use impl_converter_helper_derive::{FromConverter, TryFromConverter};
+
 /// DON'T USE! This can only be used within the [force_from] macro.
 #[cfg(feature = "warned")]
 #[doc(hidden)]
@@ -269,6 +787,31 @@ macro_rules! __force_from_struct_field {
     };
 }
 
+/// DON'T USE! This can only be used within the `@context` form of the [force_from] macro.
+#[cfg(feature = "warned")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __force_from_struct_field_ctx {
+    ($src:ident.$field:ident, $warnings:ident) => {
+        $crate::warned::Warned::unwrap(
+            $crate::warned::Warned::map_warnings(
+                $crate::warned::ForceInto::force_into($src.$field),
+                |w| $crate::WithContext::with_context(w, stringify!($field)),
+            ),
+            &mut $warnings,
+        )
+    };
+    ($src:ident.$field:ident, $warnings:ident => @warn $value:expr) => {
+        $crate::warned::Warned::unwrap(
+            $crate::warned::Warned::map_warnings($value, |w| $crate::WithContext::with_context(w, stringify!($field))),
+            &mut $warnings,
+        )
+    };
+    ($src:ident.$field:ident, $warnings:ident => $value:expr) => {
+        $value
+    };
+}
+
 /// DON'T USE! This can only be used within the [force_from] macro.
 #[cfg(feature = "warned")]
 #[doc(hidden)]
@@ -287,6 +830,49 @@ macro_rules! __force_from_enum_variant {
     ($variant:ident $(($($var:ident),*))? => $value:expr) => {
         $crate::warned::Warned::map_warnings($value, Into::into)
     };
+    ($variant:ident as $dst_variant:ident) => {
+        Self::$dst_variant.into()
+    };
+    ($variant:ident($($var:ident),*) as $dst_variant:ident($($dst_var:ident),*)) => {{
+        use $crate::warned::Warned;
+        let mut warnings = vec![];
+        let value = Self::$dst_variant($(Warned::unwrap($crate::warned::ForceInto::force_into($var), &mut warnings)),*);
+        Warned::new(value, warnings)
+    }};
+}
+
+/// DON'T USE! This can only be used within the `@context` form of the [force_from] macro.
+#[cfg(feature = "warned")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __force_from_enum_variant_ctx {
+    ($variant:ident) => {
+        Self::$variant.into()
+    };
+    ($variant:ident($($var:ident),*)) => {{
+        use $crate::warned::Warned;
+        let mut warnings = vec![];
+        let value = Self::$variant($(Warned::unwrap(
+            Warned::map_warnings($crate::warned::ForceInto::force_into($var), |w| $crate::WithContext::with_context(w, stringify!($variant))),
+            &mut warnings,
+        )),*);
+        Warned::new(value, warnings)
+    }};
+    ($variant:ident $(($($var:ident),*))? => $value:expr) => {
+        $crate::warned::Warned::map_warnings($value, Into::into)
+    };
+    ($variant:ident as $dst_variant:ident) => {
+        Self::$dst_variant.into()
+    };
+    ($variant:ident($($var:ident),*) as $dst_variant:ident($($dst_var:ident),*)) => {{
+        use $crate::warned::Warned;
+        let mut warnings = vec![];
+        let value = Self::$dst_variant($(Warned::unwrap(
+            Warned::map_warnings($crate::warned::ForceInto::force_into($var), |w| $crate::WithContext::with_context(w, stringify!($variant))),
+            &mut warnings,
+        )),*);
+        Warned::new(value, warnings)
+    }};
 }
 
 /// Helper to `impl ForceFrom<$src_type> for $dst_type`.
@@ -303,10 +889,10 @@ macro_rules! __force_from_enum_variant {
 /// struct StructB { num: i64, text: String }
 ///
 /// #[derive(Debug, PartialEq, Eq)]
-/// enum EnumA { Case1, Case2(i32), Case3(StructA, i32), Case4(String, bool) }
+/// enum EnumA { Case1, Case2(i32), Case3(StructA, i32), Case4(String, bool), Case5(i32) }
 ///
 /// #[derive(Debug, PartialEq, Eq)]
-/// enum EnumB { Case1, Case2(i64), Case3(StructB, i64) }
+/// enum EnumB { Case1, Case2(i64), Case3(StructB, i64), CaseY(i64) }
 ///
 /// #[derive(Debug, PartialEq, Eq)]
 /// struct CollectionA { items: Vec<EnumA> };
@@ -326,9 +912,12 @@ macro_rules! __force_from_enum_variant {
 ///     Case1,
 ///     Case2(n),
 ///     Case3(x, n),
-///     Case4(s, b) => warned::Warned::new(Self::Case1, vec![anyhow::anyhow!("fallback to Case1")])
+///     Case4(s, b) => warned::Warned::new(Self::Case1, vec![anyhow::anyhow!("fallback to Case1")]),
+///     // `as` renames the variant while still converting each field with `ForceInto::force_into`
+///     Case5(n) as CaseY(n),
 /// });
 /// assert_eq!(EnumB::Case2(321), EnumA::Case2(321).force_into().value);
+/// assert_eq!(EnumB::CaseY(7), EnumA::Case5(7).force_into().value);
 ///
 /// // convert anyway
 /// force_from!((src: StructA) -> <EnumA, anyhow::Error> {
@@ -341,6 +930,45 @@ macro_rules! __force_from_enum_variant {
 /// force_from!((src: CollectionA) -> <CollectionB, anyhow::Error> as struct {
 ///     items: @warn src.items.into_iter().map(ForceInto::force_into).collect()
 /// });
+///
+/// // opt into `@context` to have each field's warnings carry its field name as a breadcrumb
+/// struct Digit(i32);
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// struct DigitClamped(i32);
+///
+/// impl warned::ForceFrom<Digit> for DigitClamped {
+///     type Warning = String;
+///     fn force_from(d: Digit) -> warned::Warned<Self, String> {
+///         if (0..10).contains(&d.0) {
+///             warned::Warned::new(Self(d.0), vec![])
+///         } else {
+///             warned::Warned::new(Self(d.0.clamp(0, 9)), vec!["out of range".to_string()])
+///         }
+///     }
+/// }
+///
+/// struct NumA { value: Digit }
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// struct NumB { value: DigitClamped }
+///
+/// force_from!((src: NumA) -> <NumB, String> as struct @context {
+///     value,
+/// });
+/// use impl_converter_helper::warned::ForceFrom;
+/// let warned = NumB::force_from(NumA { value: Digit(42) });
+/// assert_eq!(vec!["value: out of range".to_string()], warned.warnings);
+///
+/// // convert a generic type, with an optional `where` clause
+/// struct Wrapper<T>(T);
+/// struct Labeled<T>(T);
+/// force_from!([T: warned::ForceInto<U>, U] (src: Wrapper<T>) -> <Labeled<U>, anyhow::Error> {
+///     let mut warnings = vec![];
+///     let value = Labeled(warned::Warned::unwrap(src.0.force_into(), &mut warnings));
+///     warned::Warned::new(value, warnings)
+/// } where { T: Clone });
+/// assert_eq!(42i64, Labeled::<i64>::force_from(Wrapper(42i32)).value.0);
 /// ```
 #[cfg(feature = "warned")]
 #[macro_export]
@@ -366,15 +994,284 @@ macro_rules! force_from {
         });
     };
 
+    // convert struct type, defaulting unlisted target fields via a `..` spread tail
+    (($src:ident : $src_type:ty) -> <$dst_type:ty, $warn_type:ty> as struct {
+        $($field:ident$(: $(@$warn:ident)? $value:expr)?,)* .. $($base:expr)?$(,)?
+    }) => {
+        $crate::force_from!(($src: $src_type) -> <$dst_type, $warn_type> {
+            let mut warnings: Vec<$warn_type> = vec![];
+            let value = Self {
+                $($field: $crate::__force_from_struct_field!($src.$field, warnings $(=> $(@$warn)? $value)?),)*
+                .. $crate::__struct_spread_base!($($base)?)
+            };
+            $crate::warned::Warned::new(value, warnings)
+        });
+    };
+
     // convert enum type
     (($src:ident : $src_type:ty) -> <$dst_type:ty, $warn_type:ty> as enum {
-        $($variant:ident$(($($var:ident),*))?$(=> $value:expr)?),*$(,)?
+        $($variant:ident$(($($var:ident),*))?$(as $dst_variant:ident $(($($dst_var:ident),*))?)?$(=> $value:expr)?),*$(,)?
+    }) => {
+        $crate::force_from!(($src: $src_type) -> <$dst_type, $warn_type> {
+            type Src = $src_type;
+            match $src {
+                $(Src::$variant$(($($var),*))? => $crate::__force_from_enum_variant!($variant$(($($var),*))? $(=> $value)? $(as $dst_variant $(($($dst_var),*))?)?),)*
+            }
+        });
+    };
+
+    // convert struct type, wrapping each field's warnings with its field name as a breadcrumb
+    (($src:ident : $src_type:ty) -> <$dst_type:ty, $warn_type:ty> as struct @context {
+        $($field:ident$(: $(@$warn:ident)? $value:expr)?),*$(,)?
+    }) => {
+        $crate::force_from!(($src: $src_type) -> <$dst_type, $warn_type> {
+            let mut warnings: Vec<$warn_type> = vec![];
+            let value = Self {
+                $($field: $crate::__force_from_struct_field_ctx!($src.$field, warnings $(=> $(@$warn)? $value)?),)*
+            };
+            $crate::warned::Warned::new(value, warnings)
+        });
+    };
+
+    // convert struct type, wrapping each field's warnings with its field name as a breadcrumb,
+    // defaulting unlisted target fields via a `..` spread tail
+    (($src:ident : $src_type:ty) -> <$dst_type:ty, $warn_type:ty> as struct @context {
+        $($field:ident$(: $(@$warn:ident)? $value:expr)?,)* .. $($base:expr)?$(,)?
+    }) => {
+        $crate::force_from!(($src: $src_type) -> <$dst_type, $warn_type> {
+            let mut warnings: Vec<$warn_type> = vec![];
+            let value = Self {
+                $($field: $crate::__force_from_struct_field_ctx!($src.$field, warnings $(=> $(@$warn)? $value)?),)*
+                .. $crate::__struct_spread_base!($($base)?)
+            };
+            $crate::warned::Warned::new(value, warnings)
+        });
+    };
+
+    // convert enum type, wrapping each variant's warnings with its variant name as a breadcrumb
+    (($src:ident : $src_type:ty) -> <$dst_type:ty, $warn_type:ty> as enum @context {
+        $($variant:ident$(($($var:ident),*))?$(as $dst_variant:ident $(($($dst_var:ident),*))?)?$(=> $value:expr)?),*$(,)?
     }) => {
         $crate::force_from!(($src: $src_type) -> <$dst_type, $warn_type> {
             type Src = $src_type;
             match $src {
-                $(Src::$variant$(($($var),*))? => $crate::__force_from_enum_variant!($variant$(($($var),*))? $(=> $value)?),)*
+                $(Src::$variant$(($($var),*))? => $crate::__force_from_enum_variant_ctx!($variant$(($($var),*))? $(=> $value)? $(as $dst_variant $(($($dst_var),*))?)?),)*
             }
         });
     };
+
+    // impl ForceFrom<$src_type> for $dst_type, with generics and an optional where clause
+    ([$($generics:tt)*] ($src:ident : $src_type:ty) -> <$dst_type:ty, $warn_type:ty> $block:block $(where { $($where_clause:tt)* })?) => {
+        impl<$($generics)*> $crate::warned::ForceFrom<$src_type> for $dst_type $(where $($where_clause)*)? {
+            type Warning = $warn_type;
+            fn force_from($src: $src_type) -> $crate::warned::Warned<Self, Self::Warning> $block
+        }
+    };
+
+    // convert struct type, with generics and an optional where clause
+    ([$($generics:tt)*] ($src:ident : $src_type:ty) -> <$dst_type:ty, $warn_type:ty> as struct {
+        $($field:ident$(: $(@$warn:ident)? $value:expr)?),*$(,)?
+    } $(where { $($where_clause:tt)* })?) => {
+        $crate::force_from!([$($generics)*] ($src: $src_type) -> <$dst_type, $warn_type> {
+            let mut warnings: Vec<$warn_type> = vec![];
+            let value = Self {
+                $($field: $crate::__force_from_struct_field!($src.$field, warnings $(=> $(@$warn)? $value)?),)*
+            };
+            $crate::warned::Warned::new(value, warnings)
+        } $(where { $($where_clause)* })?);
+    };
+
+    // convert struct type, with generics and an optional where clause, defaulting unlisted
+    // target fields via a `..` spread tail
+    ([$($generics:tt)*] ($src:ident : $src_type:ty) -> <$dst_type:ty, $warn_type:ty> as struct {
+        $($field:ident$(: $(@$warn:ident)? $value:expr)?,)* .. $($base:expr)?$(,)?
+    } $(where { $($where_clause:tt)* })?) => {
+        $crate::force_from!([$($generics)*] ($src: $src_type) -> <$dst_type, $warn_type> {
+            let mut warnings: Vec<$warn_type> = vec![];
+            let value = Self {
+                $($field: $crate::__force_from_struct_field!($src.$field, warnings $(=> $(@$warn)? $value)?),)*
+                .. $crate::__struct_spread_base!($($base)?)
+            };
+            $crate::warned::Warned::new(value, warnings)
+        } $(where { $($where_clause)* })?);
+    };
+
+    // convert enum type, with generics and an optional where clause
+    ([$($generics:tt)*] ($src:ident : $src_type:ty) -> <$dst_type:ty, $warn_type:ty> as enum {
+        $($variant:ident$(($($var:ident),*))?$(as $dst_variant:ident $(($($dst_var:ident),*))?)?$(=> $value:expr)?),*$(,)?
+    } $(where { $($where_clause:tt)* })?) => {
+        $crate::force_from!([$($generics)*] ($src: $src_type) -> <$dst_type, $warn_type> {
+            type Src = $src_type;
+            match $src {
+                $(Src::$variant$(($($var),*))? => $crate::__force_from_enum_variant!($variant$(($($var),*))? $(=> $value)? $(as $dst_variant $(($($dst_var),*))?)?),)*
+            }
+        } $(where { $($where_clause)* })?);
+    };
+}
+
+// ----------------------------------------------------------------
+
+/// Helper to lift an existing element-level `From`/`TryFrom`/`ForceFrom` impl to a named
+/// function over `Vec`, `Option` or `HashMap` of that element.
+///
+/// Given a conversion already implemented for the element type (e.g. via [from], [try_from] or
+/// [force_from]), this generates a function with the name you give it that applies the
+/// conversion across a `Vec<_>`/`Option<_>`/`HashMap<K, _>` of that element, so you don't have
+/// to repeat `.into_iter().map(...).collect()` at every call site that embeds a collection of
+/// convertible values.
+///
+/// A function is generated rather than a `From`/`TryFrom`/`ForceFrom` impl on `Vec`/`Option`/
+/// `HashMap` directly because Rust's orphan rules forbid it: neither the container nor the
+/// trait is local to your crate, and your element type doesn't count as local once it's nested
+/// inside the container's type parameter. You therefore need to name the function explicitly
+/// instead of writing `.into()`.
+///
+/// # Example
+/// ```
+/// use impl_converter_helper::*;
+/// use std::collections::HashMap;
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// struct Meters(i32);
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// struct Centimeters(i32);
+///
+/// from!((src: Meters) -> Centimeters { Centimeters(src.0 * 100) });
+///
+/// // fn lift_vec(Vec<Meters>) -> Vec<Centimeters>
+/// lift!(fn lift_vec (src: Vec<Meters>) -> Vec<Centimeters>);
+/// assert_eq!(vec![Centimeters(100), Centimeters(200)], lift_vec(vec![Meters(1), Meters(2)]));
+///
+/// // fn lift_option(Option<Meters>) -> Option<Centimeters>
+/// lift!(fn lift_option (src: Option<Meters>) -> Option<Centimeters>);
+/// assert_eq!(Some(Centimeters(100)), lift_option(Some(Meters(1))));
+///
+/// // fn lift_map(HashMap<String, Meters>) -> HashMap<String, Centimeters>
+/// lift!(fn lift_map (src: HashMap<String, Meters>) -> HashMap<String, Centimeters>);
+/// let meters = HashMap::from([("a".to_string(), Meters(1))]);
+/// let centimeters = lift_map(meters);
+/// assert_eq!(Some(&Centimeters(100)), centimeters.get("a"));
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// struct Millimeters(i32);
+///
+/// // TryFrom, collecting the first error
+/// try_from!((src: Meters) -> <Millimeters, String> {
+///     if src.0 >= 0 { Ok(Millimeters(src.0 * 1000)) } else { Err("negative".to_string()) }
+/// });
+/// lift!(try fn try_lift_vec (src: Vec<Meters>) -> <Vec<Millimeters>, String>);
+/// assert_eq!(Err("negative".to_string()), try_lift_vec(vec![Meters(1), Meters(-1)]));
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// struct Decimeters(i32);
+///
+/// // ForceFrom, aggregating every element's warnings into one `Warned`
+/// force_from!((src: Meters) -> <Decimeters, String> {
+///     if src.0 >= 0 {
+///         warned::Warned::new(Decimeters(src.0 * 10), vec![])
+///     } else {
+///         warned::Warned::new(Decimeters(0), vec!["clamped to 0".to_string()])
+///     }
+/// });
+/// lift!(force fn force_lift_vec (src: Vec<Meters>) -> <Vec<Decimeters>, String>);
+/// let warned = force_lift_vec(vec![Meters(1), Meters(-1)]);
+/// assert_eq!(vec![Decimeters(10), Decimeters(0)], warned.value);
+/// assert_eq!(vec!["clamped to 0".to_string()], warned.warnings);
+/// ```
+#[macro_export]
+macro_rules! lift {
+    // From: Vec<A> -> Vec<B>
+    (fn $name:ident ($src:ident : Vec<$src_elem:ty>) -> Vec<$dst_elem:ty>) => {
+        fn $name($src: Vec<$src_elem>) -> Vec<$dst_elem> {
+            $src.into_iter().map(::std::convert::Into::into).collect()
+        }
+    };
+
+    // From: Option<A> -> Option<B>
+    (fn $name:ident ($src:ident : Option<$src_elem:ty>) -> Option<$dst_elem:ty>) => {
+        fn $name($src: Option<$src_elem>) -> Option<$dst_elem> {
+            $src.map(::std::convert::Into::into)
+        }
+    };
+
+    // From: HashMap<K, A> -> HashMap<K, B>
+    (fn $name:ident ($src:ident : HashMap<$key_type:ty, $src_elem:ty>) -> HashMap<$dst_key_type:ty, $dst_elem:ty>) => {
+        fn $name(
+            $src: ::std::collections::HashMap<$key_type, $src_elem>,
+        ) -> ::std::collections::HashMap<$dst_key_type, $dst_elem> {
+            $src.into_iter().map(|(k, v)| (k, v.into())).collect()
+        }
+    };
+
+    // TryFrom: Vec<A> -> Vec<B>
+    (try fn $name:ident ($src:ident : Vec<$src_elem:ty>) -> <Vec<$dst_elem:ty>, $err_type:ty>) => {
+        fn $name($src: Vec<$src_elem>) -> ::std::result::Result<Vec<$dst_elem>, $err_type> {
+            $src.into_iter().map(::std::convert::TryInto::try_into).collect()
+        }
+    };
+
+    // TryFrom: Option<A> -> Option<B>
+    (try fn $name:ident ($src:ident : Option<$src_elem:ty>) -> <Option<$dst_elem:ty>, $err_type:ty>) => {
+        fn $name($src: Option<$src_elem>) -> ::std::result::Result<Option<$dst_elem>, $err_type> {
+            $src.map(::std::convert::TryInto::try_into).transpose()
+        }
+    };
+
+    // TryFrom: HashMap<K, A> -> HashMap<K, B>
+    (try fn $name:ident ($src:ident : HashMap<$key_type:ty, $src_elem:ty>) -> <HashMap<$dst_key_type:ty, $dst_elem:ty>, $err_type:ty>) => {
+        fn $name(
+            $src: ::std::collections::HashMap<$key_type, $src_elem>,
+        ) -> ::std::result::Result<::std::collections::HashMap<$dst_key_type, $dst_elem>, $err_type> {
+            $src.into_iter()
+                .map(|(k, v)| ::std::convert::TryInto::try_into(v).map(|v| (k, v)))
+                .collect()
+        }
+    };
+
+    // ForceFrom forms require the "warned" feature.
+    (force $($rest:tt)*) => {
+        $crate::__lift_force!($($rest)*);
+    };
+}
+
+/// DON'T USE! This can only be used within the `force` form of the [lift] macro.
+#[cfg(feature = "warned")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lift_force {
+    // ForceFrom: Vec<A> -> Vec<B>, aggregating every element's warnings into one `Warned`
+    (fn $name:ident ($src:ident : Vec<$src_elem:ty>) -> <Vec<$dst_elem:ty>, $warn_type:ty>) => {
+        fn $name($src: Vec<$src_elem>) -> $crate::warned::Warned<Vec<$dst_elem>, $warn_type> {
+            let mut warnings: Vec<$warn_type> = vec![];
+            let value = $src
+                .into_iter()
+                .map(|x| $crate::warned::Warned::unwrap($crate::warned::ForceInto::force_into(x), &mut warnings))
+                .collect();
+            $crate::warned::Warned::new(value, warnings)
+        }
+    };
+
+    // ForceFrom: Option<A> -> Option<B>, aggregating every element's warnings into one `Warned`
+    (fn $name:ident ($src:ident : Option<$src_elem:ty>) -> <Option<$dst_elem:ty>, $warn_type:ty>) => {
+        fn $name($src: Option<$src_elem>) -> $crate::warned::Warned<Option<$dst_elem>, $warn_type> {
+            let mut warnings: Vec<$warn_type> = vec![];
+            let value = $src.map(|x| $crate::warned::Warned::unwrap($crate::warned::ForceInto::force_into(x), &mut warnings));
+            $crate::warned::Warned::new(value, warnings)
+        }
+    };
+
+    // ForceFrom: HashMap<K, A> -> HashMap<K, B>, aggregating every element's warnings into one `Warned`
+    (fn $name:ident ($src:ident : HashMap<$key_type:ty, $src_elem:ty>) -> <HashMap<$dst_key_type:ty, $dst_elem:ty>, $warn_type:ty>) => {
+        fn $name(
+            $src: ::std::collections::HashMap<$key_type, $src_elem>,
+        ) -> $crate::warned::Warned<::std::collections::HashMap<$dst_key_type, $dst_elem>, $warn_type> {
+            let mut warnings: Vec<$warn_type> = vec![];
+            let value = $src
+                .into_iter()
+                .map(|(k, v)| (k, $crate::warned::Warned::unwrap($crate::warned::ForceInto::force_into(v), &mut warnings)))
+                .collect();
+            $crate::warned::Warned::new(value, warnings)
+        }
+    };
 }